@@ -1,5 +1,5 @@
 use anyhow::{Result, anyhow};
-use clap::Parser;
+use clap::{CommandFactory, Parser};
 
 use crate::{core::{player::Player, prompt::Prompt as PromptUtil, manifest::Manifest, text::{Translations, TextContext}, choice::Notes, resources::{UnlockedInfoPages, InfoPages, Resources}, audio::Audio}, game::{gloop::GameLoopResult}, loading::saves::SaveManager};
 
@@ -8,14 +8,20 @@ use crate::{core::{player::Player, prompt::Prompt as PromptUtil, manifest::Manif
 pub enum RuntimeCommand {
 	#[command(about = "Try going back a choice")]
 	Back,
+	#[command(about = "Try going forward a choice")]
+	Forward,
 	#[command(about = "Manage the display language")]
 	Lang,
 	#[command(about = "Display an info page")]
 	Info,
 	#[command(about = "Display an action log page")]
 	Log,
+	#[command(about = "Export the action log to a file")]
+	Export,
 	#[command(about = "Manage sound effects and music channels")]
 	Sound,
+	#[command(about = "Play a sound effect")]
+	Play,
 	#[command(about = "Save the player data")]
 	Save,
 	#[command(about = "Save and quits the game")]
@@ -47,20 +53,29 @@ impl RuntimeCommand {
 	fn is_normal(&self) -> bool {
 		use RuntimeCommand::*;
 		match self {
-			Back | Lang | Info | Log | Sound | Save | Quit => true,
+			Back | Forward | Lang | Info | Log | Export | Sound | Play | Save | Quit => true,
 			_ => false
 		}
 	}
 
 	/// Handles a [`Back`](RuntimeCommand::Back) command.
 	fn back(player: &mut Player) -> Result<CommandResult> {
-		if player.history.len() <= 1 {
+		if !player.can_go_back() {
 			return Err(anyhow!("Can't go back right now!"));
 		}
 		player.back()?;
 		Ok(CommandResult::Submit(GameLoopResult::Continue))
 	}
 
+	/// Handles a [`Forward`](RuntimeCommand::Forward) command.
+	fn forward(player: &mut Player) -> Result<CommandResult> {
+		if !player.can_go_forward() {
+			return Err(anyhow!("Can't go forward right now!"));
+		}
+		player.forward()?;
+		Ok(CommandResult::Submit(GameLoopResult::Continue))
+	}
+
 	/// Handles a [`Lang`](RuntimeCommand::Lang) command.
 	fn lang(player: &mut Player, translations: &Translations) -> Result<CommandResult> {
 		if translations.is_empty() {
@@ -107,7 +122,7 @@ impl RuntimeCommand {
 
 		let pages: Vec<&[String]> = log.chunks(5).collect();
 		let page_choices: Vec<String> = pages.iter()
-			.map(|chunk| chunk[0][..25].to_owned())
+			.map(|chunk| Self::truncate_chars(&chunk[0], 25).to_owned())
 			.map(|line| format!("{line}..."))
 			.collect();
 		let page_question = requestty::Question::raw_select("Log page")
@@ -120,6 +135,62 @@ impl RuntimeCommand {
 		Ok(CommandResult::Output(format!("\n{entries}")))
 	}
 
+	/// Truncates `s` to at most `max` characters, respecting char boundaries.
+	fn truncate_chars(s: &str, max: usize) -> &str {
+		match s.char_indices().nth(max) {
+			Some((index, _)) => &s[..index],
+			None => s
+		}
+	}
+
+	/// Handles an [`Export`](RuntimeCommand::Export) command.
+	fn export(log: &Vec<String>, text_context: &TextContext) -> Result<CommandResult> {
+		if log.is_empty() {
+			return Err(anyhow!("No log entries to export"))
+		}
+
+		println!();
+
+		let format_question = requestty::Question::select("Export format")
+			.choices(vec!["Markdown", "Plain text", "HTML"])
+			.build();
+		let format_choice = requestty::prompt_one(format_question)?;
+		let format_index = format_choice.as_list_item().unwrap().index;
+
+		let default_path = match format_index {
+			0 => "log.md",
+			1 => "log.txt",
+			2 => "log.html",
+			_ => unreachable!()
+		};
+		let path_question = requestty::Question::input("Export to")
+			.default(default_path)
+			.build();
+		let path_choice = requestty::prompt_one(path_question)?;
+		let path = path_choice.as_string().unwrap().to_owned();
+
+		let entries: Vec<String> = log.iter()
+			.map(|entry| text_context.interpolate(entry))
+			.try_collect()?;
+
+		let contents = match format_index {
+			0 => entries.iter().enumerate()
+				.map(|(i, entry)| format!("## Entry {}\n\n{entry}", i + 1))
+				.collect::<Vec<String>>()
+				.join("\n\n"),
+			1 => entries.join("\n\n"),
+			2 => entries.iter()
+				.map(|entry| format!("<p>{entry}</p>"))
+				.collect::<Vec<String>>()
+				.join("\n"),
+			_ => unreachable!()
+		};
+
+		std::fs::write(&path, contents)?;
+
+		Ok(CommandResult::Output(format!("Exported action log to '{path}'")))
+	}
+
 	/// Handles a [`Sound`](RuntimeCommand::Sound) command.
 	fn sound(player: &mut Player, audio_res: &Option<Audio>) -> Result<CommandResult> {
 		let audio = audio_res.as_ref()
@@ -146,6 +217,7 @@ impl RuntimeCommand {
 		for channel in audio.players.keys() {
 			if enabled_channels.contains(channel) {
 				player.channels.insert(channel.clone());
+				audio.get_player(channel)?.set_volume(player.channel_volume(channel));
 			}
 			else {
 				player.channels.remove(channel);
@@ -153,6 +225,59 @@ impl RuntimeCommand {
 			}
 		}
 
+		if !enabled_channels.is_empty() {
+			let adjust_question = requestty::Question::confirm("Adjust a channel's volume?")
+				.default(false)
+				.build();
+			let adjust_choice = requestty::prompt_one(adjust_question)?;
+
+			if adjust_choice.as_bool().unwrap() {
+				let volume_channel_question = requestty::Question::select("Select a channel")
+					.choices(enabled_channels)
+					.build();
+				let volume_channel_choice = requestty::prompt_one(volume_channel_question)?;
+				let channel = &volume_channel_choice.as_list_item().unwrap().text;
+
+				let percent_question = requestty::Question::int("Volume (0-100)")
+					.default((player.channel_volume(channel) * 100.0) as i64)
+					.build();
+				let percent_choice = requestty::prompt_one(percent_question)?;
+				let percent = percent_choice.as_int().unwrap().clamp(0, 100);
+				let volume = percent as f32 / 100.0;
+
+				player.channel_volumes.insert(channel.clone(), volume);
+				audio.get_player(channel)?.set_volume(volume);
+			}
+		}
+
+		Ok(CommandResult::retry())
+	}
+
+	/// Handles a [`Play`](RuntimeCommand::Play) command.
+	fn play(player: &Player, audio_res: &Option<Audio>) -> Result<CommandResult> {
+		let audio = audio_res.as_ref()
+			.ok_or(anyhow!("No sound effects loaded"))?;
+
+		if audio.effects.is_empty() {
+			return Err(anyhow!("No sound effects loaded"))
+		}
+
+		println!();
+
+		let effect_question = requestty::Question::select("Select a sound effect")
+			.choices(audio.effects.keys())
+			.build();
+		let effect_choice = requestty::prompt_one(effect_question)?;
+		let effect = &effect_choice.as_list_item().unwrap().text;
+
+		// One-shot effects play detached from the looping channels, so they don't get added
+		// to `player.channels` and aren't stopped or toggled by the Sound command. If the
+		// effect is tied to a channel, it inherits that channel's volume instead of full volume.
+		let volume = audio.effect_channel(effect)
+			.map(|channel| player.channel_volume(channel))
+			.unwrap_or(1.0);
+		audio.play_effect(effect, volume)?;
+
 		Ok(CommandResult::retry())
 	}
 
@@ -197,6 +322,46 @@ impl RuntimeCommand {
 		Ok(CommandResult::Output(format!("\n{vars}")))
 	}
 
+	/// Builds the list of command names available for completion, hiding debug-only commands
+	/// unless `debug` is enabled.
+	pub fn command_names(debug: bool) -> Vec<String> {
+		Self::command().get_subcommands()
+			.filter(|command| debug || !command.is_hide_set())
+			.map(|command| command.get_name().to_owned())
+			.collect()
+	}
+
+	/// Completes the argument at `args.len()` for this command, filtered to those starting with
+	/// `partial` (the word currently being typed there).
+	///
+	/// Returns an empty vector when this command takes no arguments at that position, or when
+	/// the backing collection has no entries to complete from.
+	pub fn completions(&self, args: &[String], partial: &str, translations: &Translations, player: &Player, audio_res: &Option<Audio>, resources: &Resources) -> Vec<String> {
+		use RuntimeCommand::*;
+		let candidates: Vec<String> = match self {
+			Lang if args.is_empty() => translations.keys().cloned().collect(),
+			Info if args.is_empty() => player.info_pages.iter().cloned().collect(),
+			Sound if args.is_empty() => audio_res.as_ref()
+				.map(|audio| audio.players.keys().cloned().collect())
+				.unwrap_or_default(),
+			Play if args.is_empty() => audio_res.as_ref()
+				.map(|audio| audio.effects.keys().cloned().collect())
+				.unwrap_or_default(),
+			Prompt => match args {
+				[] => resources.prompts.keys().cloned().collect(),
+				[file] => PromptUtil::get_file(&resources.prompts, file)
+					.map(|prompts| prompts.keys().cloned().collect())
+					.unwrap_or_default(),
+				_ => Vec::new()
+			},
+			_ => Vec::new()
+		};
+
+		candidates.into_iter()
+			.filter(|candidate| candidate.starts_with(partial))
+			.collect()
+	}
+
 	/// Executes a runtime command if the player has permission to do so.
 	///
 	/// Any errors will be reported to the input loop with a retry following.
@@ -208,10 +373,13 @@ impl RuntimeCommand {
 		use CommandResult::*;
 		let result = match self {
 			Back => Self::back(player)?,
+			Forward => Self::forward(player)?,
 			Lang => Self::lang(player, &resources.translations)?,
 			Info => Self::info(&player.info_pages, &resources.info_pages)?,
 			Log => Self::log(&player.log)?,
+			Export => Self::export(&player.log, text_context)?,
 			Sound => Self::sound(player, &resources.audio)?,
+			Play => Self::play(player, &resources.audio)?,
 			Save => {
 				saves.write(player, None, false)?;
 				Output("Saving... ".to_owned())
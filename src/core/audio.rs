@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use anyhow::{Result, anyhow};
+use rodio::{Decoder, OutputStreamHandle, Sink};
+
+/// A registered one-shot sound effect for the soundboard.
+pub struct Effect {
+	/// Shared so triggering the same effect repeatedly doesn't reclone its bytes each time.
+	pub bytes: Arc<[u8]>,
+	/// The looping channel this effect should inherit its volume from, if any.
+	pub channel: Option<String>
+}
+
+/// Manages the looping music/ambience channels and the one-shot soundboard effects.
+pub struct Audio {
+	pub players: HashMap<String, Sink>,
+	pub effects: HashMap<String, Effect>,
+	stream_handle: OutputStreamHandle
+}
+
+impl Audio {
+	/// Returns the sink for a looping channel by name.
+	pub fn get_player(&self, channel: &str) -> Result<&Sink> {
+		self.players.get(channel)
+			.ok_or_else(|| anyhow!("No such sound channel: '{channel}'"))
+	}
+
+	/// Returns the channel a soundboard effect should inherit its volume from, if it's
+	/// associated with one.
+	pub fn effect_channel(&self, effect: &str) -> Option<&str> {
+		self.effects.get(effect)?.channel.as_deref()
+	}
+
+	/// Decodes `effect` and plays it once to completion on a sink detached from `players`, so
+	/// it isn't tracked as an enabled channel and keeps playing even after the prompt moves on.
+	pub fn play_effect(&self, effect: &str, volume: f32) -> Result<()> {
+		let effect = self.effects.get(effect)
+			.ok_or_else(|| anyhow!("No such sound effect: '{effect}'"))?;
+
+		let source = Decoder::new(Cursor::new(Arc::clone(&effect.bytes)))?;
+		let sink = Sink::try_new(&self.stream_handle)?;
+		sink.set_volume(volume);
+		sink.append(source);
+		sink.detach();
+
+		Ok(())
+	}
+}
@@ -0,0 +1,100 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{Result, anyhow};
+
+/// A snapshot of the player's state at one visited prompt, kept so [`Player::back`] and
+/// [`Player::forward`] can re-render it without recomputing which notes or variables were
+/// active there.
+#[derive(Debug, Clone)]
+pub struct HistoryEntry {
+	pub file: String,
+	pub prompt: String,
+	pub notes: crate::core::choice::Notes,
+	pub variables: HashMap<String, String>
+}
+
+/// Persistent and runtime state for a single player.
+pub struct Player {
+	pub lang: String,
+	pub channels: HashSet<String>,
+	/// Per-channel playback volume (0.0-1.0), defaulting to full volume when unset.
+	pub channel_volumes: HashMap<String, f32>,
+	pub history: Vec<HistoryEntry>,
+	/// Distance of the currently displayed prompt from the end of `history`. `0` means the
+	/// player is at the most recently visited prompt; a nonzero value means `Back` has been
+	/// used without a new choice truncating the forward entries yet.
+	pub history_index: usize,
+	pub notes: crate::core::choice::Notes,
+	pub variables: HashMap<String, String>,
+	pub log: Vec<String>,
+	pub info_pages: crate::core::resources::UnlockedInfoPages,
+}
+
+impl Player {
+	/// Returns the volume for `channel`, defaulting to full volume if none has been set.
+	pub fn channel_volume(&self, channel: &str) -> f32 {
+		*self.channel_volumes.get(channel).unwrap_or(&1.0)
+	}
+
+	/// Determines whether [`back`](Self::back) can be called right now.
+	pub fn can_go_back(&self) -> bool {
+		self.history_index + 1 < self.history.len()
+	}
+
+	/// Determines whether [`forward`](Self::forward) can be called right now.
+	pub fn can_go_forward(&self) -> bool {
+		self.history_index > 0
+	}
+
+	/// Moves the history cursor one entry further into the past and re-derives `notes` and
+	/// `variables` from it, without discarding anything ahead of the cursor.
+	pub fn back(&mut self) -> Result<()> {
+		if !self.can_go_back() {
+			return Err(anyhow!("Can't go back right now!"));
+		}
+		self.history_index += 1;
+		self.apply_current_entry();
+		Ok(())
+	}
+
+	/// Moves the history cursor one entry back towards the present and re-derives `notes` and
+	/// `variables` from it.
+	pub fn forward(&mut self) -> Result<()> {
+		if !self.can_go_forward() {
+			return Err(anyhow!("Can't go forward right now!"));
+		}
+		self.history_index -= 1;
+		self.apply_current_entry();
+		Ok(())
+	}
+
+	/// Records a newly visited prompt. If the cursor isn't at the end (the player went `Back`
+	/// first), the forward entries are truncated before appending, matching standard undo/redo
+	/// semantics: a new choice discards the redo branch.
+	pub fn push_history(&mut self, entry: HistoryEntry) {
+		if self.history_index > 0 {
+			let cutoff = self.history.len() - self.history_index;
+			self.history.truncate(cutoff);
+			self.history_index = 0;
+		}
+		self.history.push(entry);
+	}
+
+	/// Re-applies the notes and variables recorded at the current cursor position, so replaying
+	/// history via `Back`/`Forward` doesn't let state drift from what was originally shown.
+	fn apply_current_entry(&mut self) {
+		let entry = self.current_entry()
+			.expect("history is never empty once a prompt has been pushed")
+			.clone();
+		self.notes = entry.notes;
+		self.variables = entry.variables;
+	}
+
+	/// Returns the entry for the prompt currently displayed, i.e. the one at the cursor - not
+	/// necessarily the most recently pushed one, since `Back`/`Forward` move the cursor without
+	/// touching `history` itself.
+	pub fn current_entry(&self) -> Option<&HistoryEntry> {
+		let index = self.history.len().checked_sub(1 + self.history_index)?;
+		self.history.get(index)
+	}
+}
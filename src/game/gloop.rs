@@ -0,0 +1,11 @@
+/// The result of running one step of the runtime command loop.
+pub enum GameLoopResult {
+	/// Display whatever prompt is now at the player's history cursor, e.g. after `Back` or
+	/// `Forward` has moved it.
+	Continue,
+	/// Redraw the currently displayed prompt. [`CommandResult::retry`](crate::cmd::runtime::CommandResult::retry)
+	/// sets the `bool` to `true` after a command completes; the game loop does not read it.
+	Retry(bool),
+	/// Stop the game loop. The `bool` is whether to suppress the shutdown message.
+	Shutdown(bool)
+}
@@ -0,0 +1,62 @@
+use anyhow::{Result, anyhow};
+use clap::Parser;
+
+use crate::cmd::runtime::{CommandResult, RuntimeCommand};
+use crate::core::{manifest::Manifest, player::Player, prompt::Prompt as PromptUtil, resources::Resources, text::TextContext};
+use crate::input::controller::InputController;
+use crate::loading::saves::SaveManager;
+
+use super::gloop::GameLoopResult;
+
+/// Runs prompts and runtime commands until the player quits.
+///
+/// The prompt currently on screen is always the one at the player's history cursor
+/// ([`Player::current_entry`]), not necessarily the last one pushed - `Back`/`Forward` move
+/// the cursor without touching `history` itself. A line that isn't a recognized runtime command
+/// is treated as a choice into the current prompt, which is what records a newly-visited prompt
+/// via [`Player::push_history`].
+pub fn begin(config: &Manifest, player: &mut Player, resources: &Resources, input: &mut InputController) -> Result<bool> {
+	let saves = SaveManager { directory: config.save_directory.clone() };
+
+	loop {
+		let entry = player.current_entry()
+			.ok_or_else(|| anyhow!("No prompt to display"))?
+			.clone();
+		let prompt = PromptUtil::get(&resources.prompts, &entry.prompt, &entry.file)?;
+		let text_context = TextContext::new(&entry.notes, &entry.variables);
+
+		println!();
+		termimad::print_text(&prompt.text(&text_context)?);
+
+		let line = input.read_command(config, player, resources)?;
+
+		match RuntimeCommand::try_parse_from(line.split_whitespace()) {
+			Ok(command) => match command.run(config, player, &saves, resources, &text_context) {
+				Ok(CommandResult::Output(message)) => println!("\n{message}"),
+				Ok(CommandResult::Submit(GameLoopResult::Retry(_))) => {}
+				Ok(CommandResult::Submit(GameLoopResult::Continue)) => {}
+				Ok(CommandResult::Submit(GameLoopResult::Shutdown(silent))) => return Ok(silent),
+				// A failed command is reported and the current prompt is redrawn
+				Err(err) => println!("\n{err}")
+			},
+			// Not a recognized runtime command - resolve it as a choice into the current
+			// prompt and push whatever it leads to onto history.
+			Err(_) => {
+				let next = prompt.choose(&line, &entry, player)?;
+				player.push_history(next);
+			}
+		}
+	}
+}
+
+/// Builds the panic message shown when the game crashes mid-run.
+pub fn crash_context(config: &Manifest) -> String {
+	format!("'{}' ran into a problem and had to close", config.name)
+}
+
+/// Prints a farewell message unless `silent`.
+pub fn shutdown(_config: &Manifest, player: &Player, silent: bool) {
+	if !silent {
+		println!("\nThanks for playing! ({} entries logged)", player.log.len());
+	}
+}
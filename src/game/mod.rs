@@ -0,0 +1,2 @@
+pub mod gloop;
+pub mod main;
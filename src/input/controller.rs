@@ -0,0 +1,130 @@
+use anyhow::{Result, anyhow};
+use clap::Parser;
+use crossterm::{
+	cursor,
+	event::{self, Event, KeyCode, KeyModifiers},
+	queue,
+	terminal::{self, ClearType}
+};
+use std::io::{Stdout, Write, stdout};
+
+use crate::cmd::runtime::RuntimeCommand;
+use crate::core::{manifest::Manifest, player::Player, resources::Resources};
+
+/// Enables terminal raw mode for its lifetime, disabling it again on drop so an I/O error
+/// partway through a read can't leave the user's terminal stuck in raw mode.
+struct RawModeGuard;
+
+impl RawModeGuard {
+	fn new() -> Result<Self> {
+		terminal::enable_raw_mode()?;
+		Ok(Self)
+	}
+}
+
+impl Drop for RawModeGuard {
+	fn drop(&mut self) {
+		let _ = terminal::disable_raw_mode();
+	}
+}
+
+/// Reads runtime command lines from the terminal, handling raw-mode input so Tab can cycle
+/// through completion candidates instead of just inserting a literal tab character.
+pub struct InputController {
+	stdout: Stdout
+}
+
+impl InputController {
+	pub fn new() -> Result<Self> {
+		Ok(Self { stdout: stdout() })
+	}
+
+	/// Reads a single command line. Tab builds completion candidates for whatever's currently
+	/// typed - the command name first, then that command's arguments - and cycles through them
+	/// on repeated presses.
+	pub fn read_command(&mut self, config: &Manifest, player: &Player, resources: &Resources) -> Result<String> {
+		let mut line = String::new();
+		let mut completions: Vec<String> = Vec::new();
+		let mut completion_index = 0usize;
+
+		let _raw_mode = RawModeGuard::new()?;
+		let result = loop {
+			self.render(&line)?;
+
+			let Event::Key(key) = event::read()? else { continue };
+			match key.code {
+				KeyCode::Enter => break Ok(line),
+				KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+					break Err(anyhow!("Input cancelled"));
+				}
+				KeyCode::Backspace => {
+					line.pop();
+					completions.clear();
+				}
+				KeyCode::Tab => {
+					if completions.is_empty() {
+						completions = Self::completions(&line, config, player, resources);
+						completion_index = 0;
+					}
+
+					// Fall back gracefully - nothing to do if this word has no candidates
+					if !completions.is_empty() {
+						let candidate = completions[completion_index % completions.len()].clone();
+						line = Self::replace_last_word(&line, &candidate);
+						completion_index += 1;
+					}
+				}
+				KeyCode::Char(c) => {
+					line.push(c);
+					completions.clear();
+				}
+				_ => {}
+			}
+		};
+		drop(_raw_mode);
+		println!();
+
+		result
+	}
+
+	fn render(&mut self, line: &str) -> Result<()> {
+		queue!(self.stdout, cursor::MoveToColumn(0), terminal::Clear(ClearType::CurrentLine))?;
+		write!(self.stdout, "> {line}")?;
+		self.stdout.flush()?;
+		Ok(())
+	}
+
+	/// Builds completion candidates for whatever's currently typed in `line`: command names
+	/// (hiding debug-only ones unless `config.settings.debug`) while the command itself is
+	/// being typed, then that command's argument completer afterwards - in both cases filtered
+	/// to candidates starting with the word currently being completed. Returns an empty vector
+	/// for an unrecognized command or one with nothing left to complete.
+	fn completions(line: &str, config: &Manifest, player: &Player, resources: &Resources) -> Vec<String> {
+		let mut tokens: Vec<&str> = line.split_whitespace().collect();
+
+		// The word currently being completed: the last token if the cursor is still inside it,
+		// otherwise a fresh empty word following a trailing space.
+		let partial = if line.ends_with(char::is_whitespace) { "" } else { tokens.pop().unwrap_or("") };
+
+		if tokens.is_empty() {
+			return RuntimeCommand::command_names(config.settings.debug).into_iter()
+				.filter(|name| name.starts_with(partial))
+				.collect();
+		}
+
+		let command_name = tokens.remove(0);
+		let Ok(command) = RuntimeCommand::try_parse_from([command_name]) else {
+			return Vec::new();
+		};
+		let args: Vec<String> = tokens.into_iter().map(str::to_owned).collect();
+
+		command.completions(&args, partial, &resources.translations, player, &resources.audio, resources)
+	}
+
+	fn replace_last_word(line: &str, replacement: &str) -> String {
+		match line.rfind(char::is_whitespace) {
+			Some(index) => format!("{}{replacement}", &line[..=index]),
+			None => replacement.to_owned()
+		}
+	}
+}
@@ -0,0 +1,80 @@
+use std::{collections::{HashMap, HashSet}, fs, path::PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::core::player::Player;
+
+/// The on-disk representation of a player's save.
+///
+/// Includes `channel_volumes` alongside the enabled channel set so that volumes set through
+/// the Sound command survive a reload instead of resetting to full volume.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SaveData {
+	pub lang: String,
+	pub channels: HashSet<String>,
+	pub channel_volumes: HashMap<String, f32>,
+	pub variables: HashMap<String, String>,
+	pub log: Vec<String>,
+}
+
+impl SaveData {
+	fn from_player(player: &Player) -> Self {
+		Self {
+			lang: player.lang.clone(),
+			channels: player.channels.clone(),
+			channel_volumes: player.channel_volumes.clone(),
+			variables: player.variables.clone(),
+			log: player.log.clone()
+		}
+	}
+
+	fn apply_to(&self, player: &mut Player) {
+		player.lang = self.lang.clone();
+		player.channels = self.channels.clone();
+		player.channel_volumes = self.channel_volumes.clone();
+		player.variables = self.variables.clone();
+		player.log = self.log.clone();
+	}
+}
+
+/// Reads and writes player saves to disk.
+pub struct SaveManager {
+	pub directory: PathBuf
+}
+
+impl SaveManager {
+	/// Writes `player`'s save data to `path`, or the default save slot if `None`.
+	///
+	/// Unless `silent`, prints a confirmation once the write completes.
+	pub fn write(&self, player: &Player, path: Option<PathBuf>, silent: bool) -> Result<()> {
+		let path = path.unwrap_or_else(|| self.directory.join("save.json"));
+		let data = SaveData::from_player(player);
+		let contents = serde_json::to_string_pretty(&data)?;
+		fs::write(&path, contents)
+			.with_context(|| format!("Failed to write save to '{}'", path.display()))?;
+
+		if !silent {
+			println!("Saved to '{}'", path.display());
+		}
+
+		Ok(())
+	}
+
+	/// Reads a save from `path`, or the default save slot if `None`, and applies it to `player`.
+	///
+	/// Does nothing if the save file doesn't exist yet.
+	pub fn load(&self, player: &mut Player, path: Option<PathBuf>) -> Result<()> {
+		let path = path.unwrap_or_else(|| self.directory.join("save.json"));
+		if !path.exists() {
+			return Ok(());
+		}
+
+		let contents = fs::read_to_string(&path)
+			.with_context(|| format!("Failed to read save from '{}'", path.display()))?;
+		let data: SaveData = serde_json::from_str(&contents)?;
+		data.apply_to(player);
+
+		Ok(())
+	}
+}